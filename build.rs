@@ -0,0 +1,5 @@
+fn main() {
+    #[cfg(feature = "grpc")]
+    tonic_build::compile_protos("proto/vector_service.proto")
+        .expect("failed to compile vector_service.proto");
+}
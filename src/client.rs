@@ -0,0 +1,339 @@
+use reqwest::{Method, StatusCode};
+
+use crate::{
+    models::{
+        ClientInfo, CreatePodIndexRequest, CreateServerlessIndexRequest, PodIndexSpec, PodSpec,
+        ServerlessIndexSpec, ServerlessSpec,
+    },
+    rest::{
+        models::{Cloud, IndexDescription, Metric},
+        try_pinecone_request_json, Connection, Credentials, Index,
+    },
+    Result,
+};
+
+/// Entry point for the crate. Holds the credentials used to authenticate with Pinecone and hands
+/// out [`Index`] handles for the indexes within a project.
+pub struct Client {
+    client: reqwest::Client,
+    creds: Credentials,
+    info: ClientInfo,
+}
+
+impl Client {
+    /// Starts building a [`Client`] for the given `api_key` and `environment`. Use this instead
+    /// of [`Client::new`] to set options like [`ClientBuilder::source_tag`] before connecting.
+    pub fn builder(api_key: impl Into<String>, environment: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            api_key: api_key.into(),
+            environment: environment.into(),
+            source_tag: None,
+        }
+    }
+
+    /// Authenticates against Pinecone with the given `api_key` and `environment`, resolving the
+    /// project metadata needed to build index urls.
+    pub async fn new(api_key: impl Into<String>, environment: impl Into<String>) -> Result<Client> {
+        Self::builder(api_key, environment).build().await
+    }
+
+    /// Returns the [`ClientInfo`] resolved when this client was created.
+    pub fn info(&self) -> &ClientInfo {
+        &self.info
+    }
+
+    /// Returns a handle to the index named `name`. This does not validate that the index exists;
+    /// call [`Index::describe`] for that.
+    pub fn index(&self, name: impl Into<String>) -> Index {
+        Index::new(self, name, &self.info)
+    }
+
+    /// Returns a gRPC-backed handle to the index named `name`. See [`GrpcIndex`](crate::GrpcIndex)
+    /// for how it compares to [`Index`].
+    #[cfg(feature = "grpc")]
+    pub async fn grpc_index(&self, name: impl Into<String>) -> Result<crate::GrpcIndex> {
+        crate::GrpcIndex::new(self, name, &self.info).await
+    }
+
+    /// Provisions a new serverless index and returns a handle to it. Mirrors the REST
+    /// `POST /databases` control-plane endpoint with a serverless `spec`.
+    pub async fn create_serverless_index(
+        &self,
+        name: impl Into<String>,
+        dimension: usize,
+        metric: Metric,
+        cloud: Cloud,
+        region: impl Into<String>,
+    ) -> Result<Index> {
+        let name = name.into();
+        let request = CreateServerlessIndexRequest {
+            name: name.clone(),
+            dimension,
+            metric: metric.into(),
+            spec: ServerlessIndexSpec {
+                serverless: ServerlessSpec {
+                    cloud: cloud.into(),
+                    region: region.into(),
+                },
+            },
+        };
+        try_pinecone_request_json::<Client, CreateServerlessIndexRequest, serde_json::Value>(
+            self,
+            Method::POST,
+            StatusCode::CREATED,
+            None,
+            "/databases",
+            Some(&request),
+        )
+        .await?;
+        Ok(self.index(name))
+    }
+
+    /// Provisions a new pod-based index and returns a handle to it. Mirrors the REST
+    /// `POST /databases` control-plane endpoint with a pod `spec`.
+    pub async fn create_pod_index(
+        &self,
+        name: impl Into<String>,
+        dimension: usize,
+        metric: Metric,
+        pod_type: impl Into<String>,
+        pods: usize,
+        replicas: usize,
+    ) -> Result<Index> {
+        let name = name.into();
+        let request = CreatePodIndexRequest {
+            name: name.clone(),
+            dimension,
+            metric: metric.into(),
+            spec: PodIndexSpec {
+                pod: PodSpec {
+                    pod_type: pod_type.into(),
+                    pods,
+                    replicas,
+                },
+            },
+        };
+        try_pinecone_request_json::<Client, CreatePodIndexRequest, serde_json::Value>(
+            self,
+            Method::POST,
+            StatusCode::CREATED,
+            None,
+            "/databases",
+            Some(&request),
+        )
+        .await?;
+        Ok(self.index(name))
+    }
+
+    /// Lists every index in the project, fully described (rather than just by name).
+    pub async fn list_indexes(&self) -> Result<Vec<IndexDescription>> {
+        let names = try_pinecone_request_json::<Client, String, Vec<String>>(
+            self,
+            Method::GET,
+            StatusCode::OK,
+            None,
+            "/databases",
+            None,
+        )
+        .await?;
+        let mut descriptions = Vec::with_capacity(names.len());
+        for name in names {
+            descriptions.push(self.index(name).describe().await?);
+        }
+        Ok(descriptions)
+    }
+}
+
+impl Connection for Client {
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+    fn credentials(&self) -> &Credentials {
+        &self.creds
+    }
+    fn client_info(&self) -> &ClientInfo {
+        &self.info
+    }
+}
+
+/// Builder for [`Client`], returned by [`Client::builder`].
+pub struct ClientBuilder {
+    api_key: String,
+    environment: String,
+    source_tag: Option<String>,
+}
+
+impl ClientBuilder {
+    /// Tags every request this client issues with `source_tag`, embedded in the `User-Agent` as
+    /// `pinecone-rs/<version> (<source_tag>)`. This lets Pinecone (and your own request logs)
+    /// attribute traffic to the integration that sent it. The tag is lowercased and stripped of
+    /// characters outside `[a-z0-9_ :]` before being embedded.
+    pub fn source_tag(mut self, source_tag: impl Into<String>) -> ClientBuilder {
+        self.source_tag = Some(source_tag.into());
+        self
+    }
+
+    /// Authenticates against Pinecone, resolving the project metadata needed to build index
+    /// urls.
+    pub async fn build(self) -> Result<Client> {
+        let creds = Credentials {
+            api_key: self.api_key,
+            environment: self.environment,
+        };
+        let user_agent = build_user_agent(self.source_tag.as_deref());
+        let client = reqwest::Client::new();
+        let project_name = client
+            .get(format!(
+                "{}/actions/whoami",
+                crate::rest::controller_url(&creds.environment)
+            ))
+            .header("Api-Key", &creds.api_key)
+            .header(reqwest::header::USER_AGENT, &user_agent)
+            .send()
+            .await?
+            .json::<serde_json::Value>()
+            .await?
+            .get("project_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        Ok(Client {
+            client,
+            creds,
+            info: ClientInfo {
+                project_name,
+                user_agent,
+            },
+        })
+    }
+}
+
+fn build_user_agent(source_tag: Option<&str>) -> String {
+    match source_tag.map(sanitize_source_tag) {
+        Some(tag) if !tag.is_empty() => {
+            format!("pinecone-rs/{} ({})", env!("CARGO_PKG_VERSION"), tag)
+        }
+        _ => format!("pinecone-rs/{}", env!("CARGO_PKG_VERSION")),
+    }
+}
+
+/// Lowercases `source_tag`, strips any character outside `[a-z0-9_ :]`, and collapses runs of
+/// whitespace, so it's safe to embed in a `User-Agent` header.
+fn sanitize_source_tag(source_tag: &str) -> String {
+    let filtered: String = source_tag
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ' ' | ':'))
+        .collect();
+    filtered.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod sanitize_source_tag_tests {
+    use super::sanitize_source_tag;
+
+    #[test]
+    fn lowercases_and_strips_disallowed_chars() {
+        assert_eq!(sanitize_source_tag("My-App/2.0!"), "myapp20");
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(sanitize_source_tag("  some   tag  "), "some tag");
+    }
+
+    #[test]
+    fn empty_input_stays_empty() {
+        assert_eq!(sanitize_source_tag(""), "");
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    /// Unlike `rest::index::index_tests`, these don't depend on a pre-existing fixture index, so
+    /// rather than baking credentials in at compile time with `env!` (which fails the whole test
+    /// binary to build without them), they read the environment at runtime and skip if unset.
+    /// Run with credentials via e.g. `PINECONE_API_KEY=... PINECONE_ENV=... cargo test -- --ignored`.
+    async fn create_client() -> Option<Client> {
+        let api_key = std::env::var("PINECONE_API_KEY").ok()?;
+        let environment = std::env::var("PINECONE_ENV").ok()?;
+        Some(Client::new(api_key, environment).await.unwrap())
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires live Pinecone credentials (PINECONE_API_KEY/PINECONE_ENV)"]
+    async fn test_create_serverless_index() {
+        let Some(client) = create_client().await else {
+            return;
+        };
+        match client
+            .create_serverless_index(
+                "pinecone-rs-test-serverless",
+                8,
+                Metric::Cosine,
+                Cloud::Aws,
+                "us-east-1",
+            )
+            .await
+        {
+            Ok(index) => match index.describe().await {
+                Ok(desc) => assert_eq!(desc.database.dimension, 8),
+                Err(err) => panic!("created index but could not describe it: {:?}", err),
+            },
+            Err(crate::Error::PineconeResponseError(code, typ, msg))
+                if code == StatusCode::BAD_REQUEST || code == StatusCode::CONFLICT =>
+            {
+                // expected if the index already exists from a previous run
+                let _ = (typ, msg);
+            }
+            Err(error) => panic!("Unable to create serverless index: {:?}", error),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires live Pinecone credentials (PINECONE_API_KEY/PINECONE_ENV)"]
+    async fn test_create_pod_index() {
+        let Some(client) = create_client().await else {
+            return;
+        };
+        match client
+            .create_pod_index("pinecone-rs-test-pod", 8, Metric::Cosine, "s1.x1", 1, 1)
+            .await
+        {
+            Ok(index) => match index.describe().await {
+                Ok(desc) => assert_eq!(desc.database.dimension, 8),
+                Err(err) => panic!("created index but could not describe it: {:?}", err),
+            },
+            Err(crate::Error::PineconeResponseError(code, typ, msg))
+                if code == StatusCode::BAD_REQUEST || code == StatusCode::CONFLICT =>
+            {
+                // expected if the index already exists from a previous run
+                let _ = (typ, msg);
+            }
+            Err(error) => panic!("Unable to create pod index: {:?}", error),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires live Pinecone credentials (PINECONE_API_KEY/PINECONE_ENV)"]
+    async fn test_list_indexes() {
+        let Some(client) = create_client().await else {
+            return;
+        };
+        match client.list_indexes().await {
+            Ok(descriptions) => {
+                for description in &descriptions {
+                    assert!(!description.database.name.is_empty());
+                }
+            }
+            Err(error) => panic!("Unable to list indexes: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use reqwest::StatusCode;
+
+/// Convenience alias for a [`Result`](std::result::Result) whose error type is [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error type returned by every fallible operation in this crate. Every variant is
+/// guaranteed `Send + Sync` (see `error_is_send_sync` below), so it can be propagated out of a
+/// `tokio::spawn`ed task or across thread boundaries without a wrapping conversion.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Pinecone responded with a status code we didn't expect, along with the response's
+    /// `content-type` header (empty for endpoints read as plain text) and its raw body text.
+    #[error("pinecone responded with {0}: {2} ({1})")]
+    PineconeResponseError(StatusCode, String, String),
+    /// The underlying HTTP request failed before a response was received.
+    #[error("request error: {0}")]
+    ReqwestError(#[from] reqwest::Error),
+    /// The response body could not be deserialized into the expected type.
+    #[error("deserialization error: {0}")]
+    SerdeError(#[from] serde_json::Error),
+    /// A gRPC channel could not be established with the data plane.
+    #[cfg(feature = "grpc")]
+    #[error("grpc transport error: {0}")]
+    GrpcTransportError(#[from] tonic::transport::Error),
+    /// A gRPC call returned a non-OK status.
+    #[cfg(feature = "grpc")]
+    #[error("grpc status error: {0}")]
+    GrpcStatusError(#[from] tonic::Status),
+    /// The endpoint built from a caller-supplied index name wasn't a valid URI.
+    #[cfg(feature = "grpc")]
+    #[error("invalid grpc endpoint uri: {0}")]
+    GrpcInvalidUriError(#[from] tonic::codegen::http::uri::InvalidUri),
+    /// A credential or header value wasn't valid gRPC metadata (ASCII-only).
+    #[cfg(feature = "grpc")]
+    #[error("invalid grpc metadata value: {0}")]
+    GrpcMetadataError(#[from] tonic::metadata::errors::InvalidMetadataValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn error_is_send_sync() {
+        assert_send_sync::<Error>();
+    }
+}
@@ -0,0 +1,234 @@
+use serde_json::Value;
+use tonic::{
+    metadata::MetadataValue,
+    transport::{Channel, ClientTlsConfig},
+    Request,
+};
+
+use crate::{
+    models::{
+        ClientInfo, DeleteRequest, FetchRequest, FetchResponse, QueryMatch, QueryRequest,
+        QueryResponse, UpdateRequest,
+    },
+    rest::{
+        models::{SparseValues, UpsertResponse, Vector},
+        Connection, Credentials,
+    },
+    Result,
+};
+
+use super::proto::vector_service::{
+    vector_service_client::VectorServiceClient, DeleteRequest as ProtoDeleteRequest,
+    FetchRequest as ProtoFetchRequest, QueryRequest as ProtoQueryRequest,
+    SparseValues as ProtoSparseValues, UpdateRequest as ProtoUpdateRequest,
+    UpsertRequest as ProtoUpsertRequest, Vector as ProtoVector,
+};
+
+fn metadata_to_json(metadata: Option<Value>) -> String {
+    metadata.map(|m| m.to_string()).unwrap_or_default()
+}
+
+fn json_to_metadata(json: String) -> Option<Value> {
+    if json.is_empty() {
+        None
+    } else {
+        serde_json::from_str(&json).ok()
+    }
+}
+
+impl From<Vector> for ProtoVector {
+    fn from(value: Vector) -> Self {
+        ProtoVector {
+            id: value.id,
+            values: value.values,
+            sparse_values: value.sparse_values.map(|s| ProtoSparseValues {
+                indices: s.indices,
+                values: s.values,
+            }),
+            metadata_json: metadata_to_json(value.metadata),
+        }
+    }
+}
+
+impl From<ProtoVector> for Vector {
+    fn from(value: ProtoVector) -> Self {
+        Vector {
+            id: value.id,
+            values: value.values,
+            sparse_values: value.sparse_values.map(|s| SparseValues {
+                indices: s.indices,
+                values: s.values,
+            }),
+            metadata: json_to_metadata(value.metadata_json),
+        }
+    }
+}
+
+/// A gRPC-backed counterpart to [`Index`](crate::rest::Index), reached over Pinecone's
+/// `VectorService` data plane instead of REST, obtained from the async
+/// [`Client::grpc_index`](crate::Client::grpc_index) rather than [`Client::index`](crate::Client::index).
+/// Its `upsert`, `query`, `fetch`, `update`, `delete_vectors` and `describe_stats` take the same
+/// arguments as their `Index` counterparts, but `update` and `delete_vectors` return `()` instead
+/// of a JSON value, and there is no `upsert_batched` or `namespace` handle here.
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub struct GrpcIndex {
+    client: VectorServiceClient<Channel>,
+    name: String,
+    creds: Credentials,
+    client_info: ClientInfo,
+}
+
+impl GrpcIndex {
+    /// Establishes a single long-lived TLS channel to `{name}-{project}.svc.{env}.pinecone.io`
+    /// and reuses it for every subsequent call, rather than reconnecting per request.
+    pub(crate) async fn new<C>(
+        con: &C,
+        name: impl Into<String>,
+        client_info: &ClientInfo,
+    ) -> Result<GrpcIndex>
+    where
+        C: Connection,
+    {
+        let name = name.into();
+        let creds = con.credentials().clone();
+        let endpoint = format!(
+            "https://{}-{}.svc.{}.pinecone.io:443",
+            name, client_info.project_name, creds.environment
+        );
+        // `name` comes straight from the caller, so it may not be a valid URI (a space, `/`, or
+        // `#`); propagate that as an `Error` instead of panicking, the same as a bad name would
+        // surface as a request `Error` from the REST `Index`.
+        let channel = Channel::from_shared(endpoint)?
+            .tls_config(ClientTlsConfig::new())?
+            .connect()
+            .await?;
+        Ok(GrpcIndex {
+            client: VectorServiceClient::new(channel),
+            name,
+            creds,
+            client_info: client_info.clone(),
+        })
+    }
+
+    /// Attaches the `Api-Key` credential and `User-Agent` to an outgoing request's metadata, the
+    /// gRPC equivalent of the headers set by `rest::try_pinecone_request_json` on every REST call.
+    /// `api_key` is raw caller-supplied input, so this can fail the same way setting an invalid
+    /// REST header value surfaces as an `Err` from `reqwest` instead of panicking.
+    #[allow(clippy::result_large_err)] // shares the crate-wide `Error` type by design
+    fn authed<T>(&self, message: T) -> Result<Request<T>> {
+        let mut request = Request::new(message);
+        let api_key: MetadataValue<_> = self.creds.api_key.parse()?;
+        let user_agent: MetadataValue<_> = self.client_info.user_agent.parse()?;
+        request.metadata_mut().insert("api-key", api_key);
+        request.metadata_mut().insert("user-agent", user_agent);
+        Ok(request)
+    }
+
+    /// Mirrors [`Index::upsert`](crate::rest::Index::upsert).
+    pub async fn upsert(&self, namespace: String, vectors: Vec<Vector>) -> Result<UpsertResponse> {
+        let request = self.authed(ProtoUpsertRequest {
+            namespace,
+            vectors: vectors.into_iter().map(Into::into).collect(),
+        })?;
+        let response = self.client.clone().upsert(request).await?.into_inner();
+        Ok(UpsertResponse {
+            upserted_count: response.upserted_count as usize,
+        })
+    }
+
+    /// Mirrors [`Index::query`](crate::rest::Index::query).
+    pub async fn query(&self, request: QueryRequest) -> Result<QueryResponse> {
+        let request = self.authed(ProtoQueryRequest {
+            namespace: request.namespace.unwrap_or_default(),
+            top_k: request.top_k as u32,
+            id: request.id.unwrap_or_default(),
+            vector: request.vector.unwrap_or_default(),
+            filter_json: metadata_to_json(request.filter),
+            include_values: request.include_values,
+            include_metadata: request.include_metadata,
+        })?;
+        let response = self.client.clone().query(request).await?.into_inner();
+        Ok(QueryResponse {
+            matches: response
+                .matches
+                .into_iter()
+                .map(|m| QueryMatch {
+                    id: m.id,
+                    score: m.score,
+                    values: (!m.values.is_empty()).then_some(m.values),
+                    metadata: json_to_metadata(m.metadata_json),
+                })
+                .collect(),
+            namespace: (!response.namespace.is_empty()).then_some(response.namespace),
+        })
+    }
+
+    /// Mirrors [`Index::fetch`](crate::rest::Index::fetch).
+    pub async fn fetch(&self, request: FetchRequest) -> Result<FetchResponse> {
+        let request = self.authed(ProtoFetchRequest {
+            ids: request.ids,
+            namespace: request.namespace.unwrap_or_default(),
+        })?;
+        let response = self.client.clone().fetch(request).await?.into_inner();
+        Ok(FetchResponse {
+            vectors: response
+                .vectors
+                .into_iter()
+                .map(|(id, v)| (id, v.into()))
+                .collect(),
+            namespace: (!response.namespace.is_empty()).then_some(response.namespace),
+        })
+    }
+
+    /// Mirrors [`Index::update`](crate::rest::Index::update).
+    pub async fn update(&self, request: UpdateRequest) -> Result<()> {
+        let request = self.authed(ProtoUpdateRequest {
+            id: request.id,
+            values: request.values.unwrap_or_default(),
+            set_metadata_json: metadata_to_json(request.set_metadata),
+            namespace: request.namespace.unwrap_or_default(),
+        })?;
+        self.client.clone().update(request).await?;
+        Ok(())
+    }
+
+    /// Mirrors [`Index::delete_vectors`](crate::rest::Index::delete_vectors).
+    pub async fn delete_vectors(&self, request: DeleteRequest) -> Result<()> {
+        let request = self.authed(ProtoDeleteRequest {
+            ids: request.ids,
+            delete_all: request.delete_all,
+            namespace: request.namespace.unwrap_or_default(),
+            filter_json: metadata_to_json(request.filter),
+        })?;
+        self.client.clone().delete(request).await?;
+        Ok(())
+    }
+
+    /// Mirrors [`Index::describe_stats`](crate::rest::Index::describe_stats).
+    pub async fn describe_stats(&self) -> Result<crate::rest::models::IndexStats> {
+        let request = self.authed(super::proto::vector_service::DescribeIndexStatsRequest {
+            filter_json: String::new(),
+        })?;
+        let response = self
+            .client
+            .clone()
+            .describe_index_stats(request)
+            .await?
+            .into_inner();
+        Ok(crate::rest::models::IndexStats {
+            namespaces: response
+                .namespaces
+                .into_iter()
+                .map(|(k, v)| (k, serde_json::json!({ "vector_count": v.vector_count })))
+                .collect(),
+            dimension: response.dimension as usize,
+            index_fullness: response.index_fullness,
+            total_vector_count: response.total_vector_count as usize,
+        })
+    }
+
+    /// The index name this handle was created for.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,8 @@
+//! gRPC data-plane transport, enabled by the `grpc` feature. [`GrpcIndex`] talks to the same
+//! Pinecone data plane as [`Index`](crate::rest::Index) but over the `VectorService` gRPC API,
+//! which is lower-latency for large batch upserts and high-QPS queries.
+
+mod index;
+pub(crate) mod proto;
+
+pub use index::GrpcIndex;
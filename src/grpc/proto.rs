@@ -0,0 +1,6 @@
+//! Generated protobuf/gRPC bindings for the `VectorService` data plane, built from
+//! `proto/vector_service.proto` by `build.rs` when the `grpc` feature is enabled.
+
+pub(crate) mod vector_service {
+    tonic::include_proto!("vector_service");
+}
@@ -0,0 +1,15 @@
+mod client;
+mod error;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod models;
+mod namespace;
+pub mod rest;
+
+pub use client::{Client, ClientBuilder};
+pub use error::{Error, Result};
+pub use namespace::{Namespace, NamespaceHandle};
+pub use rest::{BatchConfig, BatchUpsertResult, Connection, Credentials, Index};
+
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcIndex;
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Identifies the application or integration issuing requests, and carries the project metadata
+/// resolved from the caller's credentials.
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    pub project_name: String,
+    /// The `User-Agent` sent with every request, built by
+    /// [`ClientBuilder::build`](crate::ClientBuilder::build) from the optional
+    /// [`ClientBuilder::source_tag`](crate::ClientBuilder::source_tag).
+    pub user_agent: String,
+}
+
+/// Body for [`Index::configure`](crate::rest::Index::configure).
+#[derive(Debug, Serialize)]
+pub struct ConfigureIndexRequest {
+    pub replicas: usize,
+    pub pod_type: String,
+}
+
+/// Body for [`Client::create_serverless_index`](crate::Client::create_serverless_index).
+#[derive(Debug, Serialize)]
+pub struct CreateServerlessIndexRequest {
+    pub name: String,
+    pub dimension: usize,
+    pub metric: String,
+    pub spec: ServerlessIndexSpec,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerlessIndexSpec {
+    pub serverless: ServerlessSpec,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerlessSpec {
+    pub cloud: String,
+    pub region: String,
+}
+
+/// Body for [`Client::create_pod_index`](crate::Client::create_pod_index).
+#[derive(Debug, Serialize)]
+pub struct CreatePodIndexRequest {
+    pub name: String,
+    pub dimension: usize,
+    pub metric: String,
+    pub spec: PodIndexSpec,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PodIndexSpec {
+    pub pod: PodSpec,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PodSpec {
+    pub pod_type: String,
+    pub pods: usize,
+    pub replicas: usize,
+}
+
+/// Parameters for [`Index::fetch`](crate::rest::Index::fetch). Pinecone's fetch endpoint takes
+/// its arguments as query parameters rather than a JSON body, so this builds its own URL.
+#[derive(Debug, Default)]
+pub struct FetchRequest {
+    pub ids: Vec<String>,
+    pub namespace: Option<String>,
+}
+
+impl FetchRequest {
+    /// Builds the `/vectors/fetch` URL for this request against the given index `base` url.
+    pub fn url(&self, base: String) -> String {
+        let mut url = format!("{}/vectors/fetch?", base);
+        for id in &self.ids {
+            url.push_str(&format!("ids={}&", id));
+        }
+        if let Some(namespace) = &self.namespace {
+            url.push_str(&format!("namespace={}", namespace));
+        }
+        url
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchResponse {
+    pub vectors: std::collections::HashMap<String, crate::rest::models::Vector>,
+    pub namespace: Option<String>,
+}
+
+/// Body for [`Index::query`](crate::rest::Index::query).
+#[derive(Debug, Default, Serialize)]
+pub struct QueryRequest {
+    pub id: Option<String>,
+    pub vector: Option<Vec<f32>>,
+    pub top_k: usize,
+    pub namespace: Option<String>,
+    pub filter: Option<Value>,
+    pub include_values: bool,
+    pub include_metadata: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QueryMatch {
+    pub id: String,
+    pub score: f32,
+    pub values: Option<Vec<f32>>,
+    pub metadata: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QueryResponse {
+    pub matches: Vec<QueryMatch>,
+    pub namespace: Option<String>,
+}
+
+/// Body for [`Index::update`](crate::rest::Index::update).
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateRequest {
+    pub id: String,
+    pub values: Option<Vec<f32>>,
+    pub set_metadata: Option<Value>,
+    pub namespace: Option<String>,
+}
+
+/// Body for [`Index::delete_vectors`](crate::rest::Index::delete_vectors). `ids`, `delete_all`,
+/// and `filter` are mutually exclusive ways of selecting which vectors to remove.
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteRequest {
+    pub ids: Vec<String>,
+    pub delete_all: bool,
+    pub namespace: Option<String>,
+    pub filter: Option<Value>,
+}
@@ -0,0 +1,183 @@
+use crate::{
+    models::{
+        DeleteRequest, FetchRequest, FetchResponse, QueryRequest, QueryResponse, UpdateRequest,
+    },
+    rest::{
+        models::{UpsertResponse, Vector},
+        Index,
+    },
+    Result,
+};
+
+/// A namespace within an index. Namespaces partition vectors so multi-tenant code can scope
+/// queries, fetches, and upserts to a single tenant without vectors from others leaking in. The
+/// empty string is Pinecone's default, unnamed namespace.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Namespace(String);
+
+impl Namespace {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Namespace {
+    fn from(value: &str) -> Self {
+        Namespace(value.to_string())
+    }
+}
+
+impl From<String> for Namespace {
+    fn from(value: String) -> Self {
+        Namespace(value)
+    }
+}
+
+impl From<Namespace> for String {
+    fn from(value: Namespace) -> Self {
+        value.0
+    }
+}
+
+/// A handle to a single [`Namespace`] within an [`Index`], returned by [`Index::namespace`].
+/// Every method here scopes its operation to that namespace, so callers no longer have to
+/// re-specify it (and risk a typo) on every call.
+pub struct NamespaceHandle<'a> {
+    index: &'a Index,
+    namespace: Namespace,
+}
+
+impl<'a> NamespaceHandle<'a> {
+    pub(crate) fn new(index: &'a Index, namespace: Namespace) -> NamespaceHandle<'a> {
+        NamespaceHandle { index, namespace }
+    }
+
+    /// Scoped equivalent of [`Index::upsert`].
+    pub async fn upsert(&self, vectors: Vec<Vector>) -> Result<UpsertResponse> {
+        self.index
+            .upsert(self.namespace.clone().into(), vectors)
+            .await
+    }
+
+    /// Scoped equivalent of [`Index::query`].
+    pub async fn query(&self, mut request: QueryRequest) -> Result<QueryResponse> {
+        request.namespace = Some(self.namespace.as_str().to_string());
+        self.index.query(request).await
+    }
+
+    /// Scoped equivalent of [`Index::fetch`].
+    pub async fn fetch(&self, ids: Vec<String>) -> Result<FetchResponse> {
+        self.index
+            .fetch(FetchRequest {
+                ids,
+                namespace: Some(self.namespace.as_str().to_string()),
+            })
+            .await
+    }
+
+    /// Scoped equivalent of [`Index::update`].
+    pub async fn update(&self, mut request: UpdateRequest) -> Result<serde_json::Value> {
+        request.namespace = Some(self.namespace.as_str().to_string());
+        self.index.update(request).await
+    }
+
+    /// Scoped equivalent of [`Index::delete_vectors`].
+    pub async fn delete(&self, mut request: DeleteRequest) -> Result<serde_json::Value> {
+        request.namespace = Some(self.namespace.as_str().to_string());
+        self.index.delete_vectors(request).await
+    }
+}
+
+#[cfg(test)]
+mod namespace_handle_tests {
+    use super::*;
+    use crate::Client;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    /// Reads credentials from the environment at runtime and skips (rather than failing to
+    /// compile via `env!`) if they aren't set. Run with credentials via e.g.
+    /// `PINECONE_API_KEY=... PINECONE_ENV=... PINECONE_INDEX_NAME=... cargo test -- --ignored`.
+    async fn create_index() -> Option<Index> {
+        let api_key = std::env::var("PINECONE_API_KEY").ok()?;
+        let environment = std::env::var("PINECONE_ENV").ok()?;
+        let index_name = std::env::var("PINECONE_INDEX_NAME").ok()?;
+        let client = Client::new(api_key, environment).await.unwrap();
+        Some(Index::new(&client, index_name, client.info()))
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires a live Pinecone index (PINECONE_API_KEY/PINECONE_ENV/PINECONE_INDEX_NAME)"]
+    async fn test_namespace_upsert() {
+        let Some(index) = create_index().await else {
+            return;
+        };
+        let desc = match index.describe().await {
+            Ok(desc) => desc,
+            Err(err) => panic!("Unable to get dimension of index: {:?}", err),
+        };
+        let vec = Vector {
+            id: "A".to_string(),
+            values: vec![0.5; desc.database.dimension],
+            sparse_values: None,
+            metadata: None,
+        };
+        match index.namespace("halfbaked").upsert(vec![vec]).await {
+            Ok(response) => assert_eq!(response.upserted_count, 1),
+            Err(err) => panic!("unable to upsert: {:?}", err),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires a live Pinecone index (PINECONE_API_KEY/PINECONE_ENV/PINECONE_INDEX_NAME)"]
+    async fn test_namespace_fetch() {
+        let Some(index) = create_index().await else {
+            return;
+        };
+        match index
+            .namespace("halfbaked")
+            .fetch(vec!["A".to_string()])
+            .await
+        {
+            Ok(response) => assert_eq!(response.namespace.as_deref(), Some("halfbaked")),
+            Err(err) => panic!("Unable to fetch: {:?}", err),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires a live Pinecone index (PINECONE_API_KEY/PINECONE_ENV/PINECONE_INDEX_NAME)"]
+    async fn test_namespace_query() {
+        let Some(index) = create_index().await else {
+            return;
+        };
+        let data = QueryRequest {
+            id: Some(String::from("A")),
+            top_k: 1,
+            ..Default::default()
+        };
+        match index.namespace("halfbaked").query(data).await {
+            Ok(response) => assert_eq!(response.namespace.as_deref(), Some("halfbaked")),
+            Err(err) => panic!("Unable to query: {:?}", err),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[ignore = "requires a live Pinecone index (PINECONE_API_KEY/PINECONE_ENV/PINECONE_INDEX_NAME)"]
+    async fn test_namespace_delete() {
+        let Some(index) = create_index().await else {
+            return;
+        };
+        let data = DeleteRequest {
+            ids: vec!["A".to_string()],
+            ..Default::default()
+        };
+        match index.namespace("halfbaked").delete(data).await {
+            Ok(_) => {}
+            Err(err) => panic!("Unable to delete: {:?}", err),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
     models::{
-        ClientInfo, ConfigureIndexRequest, FetchRequest, FetchResponse, QueryRequest,
-        QueryResponse, UpdateRequest,
+        ClientInfo, ConfigureIndexRequest, DeleteRequest, FetchRequest, FetchResponse,
+        QueryRequest, QueryResponse, UpdateRequest,
     },
     rest::{try_pinecone_request_json, try_pinecone_request_text},
-    Result,
+    Error, Namespace, NamespaceHandle, Result,
 };
+use futures::stream::{self, StreamExt};
 use reqwest::{Method, StatusCode};
 use serde_json::Value;
 
@@ -95,9 +96,48 @@ impl Index {
         .await
     }
 
-    /// Delete will attempt to delete the current Index and return the associated Message returned
-    /// by Pinecone when successfull. This will error if the Index does not exist.
-    pub async fn delete(self) -> Result<String> {
+    /// Splits `vectors` into chunks bounded by both `config.max_records` and an estimated
+    /// `config.max_bytes`, and upserts up to `config.max_concurrency` chunks at a time. Unlike
+    /// [`Index::upsert`], a failed chunk doesn't fail the whole call: its error is collected in
+    /// [`BatchUpsertResult::errors`] alongside the count of vectors that *did* get written.
+    pub async fn upsert_batched(
+        &self,
+        namespace: String,
+        vectors: Vec<Vector>,
+        config: BatchConfig,
+    ) -> Result<BatchUpsertResult> {
+        let chunks = chunk_vectors(vectors, config.max_records, config.max_bytes);
+        // `buffer_unordered(0)` never polls any inner future and so never resolves; treat it the
+        // same as 1 (one chunk at a time) rather than hanging the caller forever.
+        let max_concurrency = config.max_concurrency.max(1);
+        let results = stream::iter(chunks.into_iter().map(|chunk| {
+            let namespace = namespace.clone();
+            async move { self.upsert(namespace, chunk).await }
+        }))
+        .buffer_unordered(max_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut upserted_count = 0;
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(response) => upserted_count += response.upserted_count,
+                Err(err) => errors.push(err),
+            }
+        }
+        Ok(BatchUpsertResult {
+            upserted_count,
+            errors,
+        })
+    }
+
+    /// Deletes the current Index and returns the associated Message returned by Pinecone when
+    /// successfull. This will error if the Index does not exist.
+    ///
+    /// This destroys the whole index, including every namespace in it. To remove individual
+    /// vectors instead, use [`Index::delete_vectors`].
+    pub async fn delete_index(self) -> Result<String> {
         try_pinecone_request_text::<Index, String>(
             &self,
             Method::DELETE,
@@ -109,6 +149,21 @@ impl Index {
         .await
     }
 
+    /// Deletes vectors from the index, either by `ids`, by a metadata `filter`, or the whole
+    /// namespace when `delete_all` is set. Unlike [`Index::delete_index`], this leaves the index
+    /// itself (and its other namespaces) intact.
+    pub async fn delete_vectors(&self, request: DeleteRequest) -> Result<Value> {
+        try_pinecone_request_json::<Index, DeleteRequest, Value>(
+            self,
+            Method::POST,
+            StatusCode::OK,
+            Some(self.url()),
+            "/vectors/delete",
+            Some(&request),
+        )
+        .await
+    }
+
     /// Configures the current index, specifically [`replicas`] and [`pod_type`] settings. More can
     /// be found at [Pinecone](https://docs.pinecone.io/reference/configure_index)
     pub async fn configure(&self, replicas: usize, pod_type: String) -> Result<String> {
@@ -166,6 +221,12 @@ impl Index {
         )
         .await
     }
+
+    /// Returns a handle scoped to `namespace`, whose `upsert`/`query`/`fetch`/`update` methods
+    /// don't require re-specifying the namespace on every call.
+    pub fn namespace(&self, namespace: impl Into<Namespace>) -> NamespaceHandle<'_> {
+        NamespaceHandle::new(self, namespace.into())
+    }
 }
 
 impl Connection for Index {
@@ -175,7 +236,129 @@ impl Connection for Index {
     fn credentials(&self) -> &Credentials {
         &self.creds
     }
+    fn client_info(&self) -> &ClientInfo {
+        &self.client_info
+    }
+}
+
+/// Tuning knobs for [`Index::upsert_batched`].
+#[derive(Debug, Clone)]
+pub struct BatchConfig {
+    /// Maximum number of vectors per chunk.
+    pub max_records: usize,
+    /// Maximum estimated serialized size, in bytes, per chunk.
+    pub max_bytes: usize,
+    /// Maximum number of chunks upserted concurrently.
+    pub max_concurrency: usize,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        BatchConfig {
+            max_records: 100,
+            max_bytes: 2 * 1024 * 1024,
+            max_concurrency: 10,
+        }
+    }
+}
+
+/// Result of [`Index::upsert_batched`]. `errors` holds one entry per chunk that failed to
+/// upsert, so a partial failure doesn't lose the count of vectors that did get written.
+#[derive(Debug)]
+pub struct BatchUpsertResult {
+    pub upserted_count: usize,
+    pub errors: Vec<Error>,
+}
+
+/// Greedily splits `vectors` into chunks no larger than `max_records` and no bigger than an
+/// estimated `max_bytes`, in order. A single vector whose estimate alone exceeds `max_bytes`
+/// still gets a chunk of its own, so that chunk is over the byte bound.
+fn chunk_vectors(vectors: Vec<Vector>, max_records: usize, max_bytes: usize) -> Vec<Vec<Vector>> {
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut current_bytes = 0;
+    for vector in vectors {
+        let size = estimate_vector_bytes(&vector);
+        if !current.is_empty() && (current.len() >= max_records || current_bytes + size > max_bytes)
+        {
+            chunks.push(std::mem::take(&mut current));
+            current_bytes = 0;
+        }
+        current_bytes += size;
+        current.push(vector);
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Rough estimate of a vector's serialized size: its dense and sparse float/index data plus its
+/// metadata, in bytes. Doesn't need to be exact, just proportional to what Pinecone counts
+/// against its request-size limit.
+fn estimate_vector_bytes(vector: &Vector) -> usize {
+    let values_bytes = vector.values.len() * std::mem::size_of::<f32>();
+    let sparse_bytes = vector
+        .sparse_values
+        .as_ref()
+        .map(|s| {
+            s.indices.len() * std::mem::size_of::<u32>()
+                + s.values.len() * std::mem::size_of::<f32>()
+        })
+        .unwrap_or(0);
+    let metadata_bytes = vector
+        .metadata
+        .as_ref()
+        .map(|m| m.to_string().len())
+        .unwrap_or(0);
+    vector.id.len() + values_bytes + sparse_bytes + metadata_bytes
+}
+
+#[cfg(test)]
+mod chunk_vectors_tests {
+    use super::*;
+
+    fn vector(id: &str, dimension: usize) -> Vector {
+        Vector {
+            id: id.to_string(),
+            values: vec![0.0; dimension],
+            sparse_values: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn empty_input_produces_no_chunks() {
+        assert!(chunk_vectors(Vec::new(), 100, 1024).is_empty());
+    }
+
+    #[test]
+    fn exactly_max_records_stays_in_one_chunk() {
+        let vectors: Vec<Vector> = (0..10).map(|i| vector(&i.to_string(), 4)).collect();
+        let chunks = chunk_vectors(vectors, 10, usize::MAX);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 10);
+    }
+
+    #[test]
+    fn one_more_than_max_records_spills_into_a_second_chunk() {
+        let vectors: Vec<Vector> = (0..11).map(|i| vector(&i.to_string(), 4)).collect();
+        let chunks = chunk_vectors(vectors, 10, usize::MAX);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 10);
+        assert_eq!(chunks[1].len(), 1);
+    }
+
+    #[test]
+    fn vector_bigger_than_max_bytes_alone_still_gets_its_own_chunk() {
+        let huge = vector("huge", 10_000);
+        let bytes = estimate_vector_bytes(&huge);
+        let chunks = chunk_vectors(vec![huge], 100, bytes - 1);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 1);
+    }
 }
+
 #[cfg(test)]
 mod index_tests {
 
@@ -321,4 +504,48 @@ mod index_tests {
             Err(error) => panic!("Unable to fetch: {:?}", error),
         }
     }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    async fn test_delete_vectors() {
+        let client = create_client().await;
+        let index = create_index(&client).await;
+        let data = DeleteRequest {
+            ids: vec!["B".to_string()],
+            namespace: Some(String::from("halfbaked")),
+            ..Default::default()
+        };
+        match index.delete_vectors(data).await {
+            Ok(_) => assert!(true),
+            Err(error) => panic!("Unable to delete vectors: {:?}", error),
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    async fn test_delete_index() {
+        // Exercises the request/response plumbing without tearing down the shared fixture index
+        // the rest of this module relies on: deleting a name that doesn't exist still reaches
+        // Pinecone and comes back as a well-formed error instead of panicking.
+        let client = create_client().await;
+        let index = client.index("pinecone-rs-test-nonexistent");
+        match index.delete_index().await {
+            Ok(_) => assert!(true),
+            Err(error) => match error {
+                Error::PineconeResponseError(code, typ, msg) => {
+                    if code == StatusCode::NOT_FOUND {
+                        assert!(true);
+                        return;
+                    }
+                    panic!(
+                        "Unable to delete index: {:?}",
+                        Error::PineconeResponseError(code, typ, msg)
+                    )
+                }
+                _ => {
+                    panic!("Unable to delete index: {:?}", error)
+                }
+            },
+        }
+    }
 }
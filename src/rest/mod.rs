@@ -0,0 +1,100 @@
+mod index;
+pub mod models;
+
+pub use index::{BatchConfig, BatchUpsertResult, Index};
+
+use reqwest::{Method, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{models::ClientInfo, Error, Result};
+
+/// Credentials used to authenticate every request issued by a [`Client`](crate::Client) or
+/// [`Index`].
+#[derive(Debug, Clone)]
+pub struct Credentials {
+    pub api_key: String,
+    pub environment: String,
+}
+
+/// Anything that can produce the [`reqwest::Client`], [`Credentials`], and [`ClientInfo`] needed
+/// to talk to Pinecone. Implemented by both [`Client`](crate::Client) and [`Index`].
+pub trait Connection {
+    fn client(&self) -> &reqwest::Client;
+    fn credentials(&self) -> &Credentials;
+    fn client_info(&self) -> &ClientInfo;
+}
+
+/// The base url for Pinecone's control plane, where index lifecycle operations live.
+pub fn controller_url(environment: &str) -> String {
+    format!("https://controller.{}.pinecone.io", environment)
+}
+
+/// Issues a request against `base` (defaulting to the control plane url) + `path`, attaching the
+/// `Api-Key` header, and deserializes the JSON response body if the status matches `expected`.
+pub(crate) async fn try_pinecone_request_json<C, Req, Resp>(
+    con: &C,
+    method: Method,
+    expected: StatusCode,
+    base: Option<String>,
+    path: impl AsRef<str>,
+    body: Option<&Req>,
+) -> Result<Resp>
+where
+    C: Connection,
+    Req: Serialize,
+    Resp: DeserializeOwned,
+{
+    let base = base.unwrap_or_else(|| controller_url(&con.credentials().environment));
+    let mut request = con
+        .client()
+        .request(method, format!("{}{}", base, path.as_ref()))
+        .header("Api-Key", &con.credentials().api_key)
+        .header(reqwest::header::USER_AGENT, &con.client_info().user_agent);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    if status != expected {
+        let typ = response
+            .headers()
+            .get("content-type")
+            .map(|v| v.to_str().unwrap_or_default().to_string())
+            .unwrap_or_default();
+        let msg = response.text().await.unwrap_or_default();
+        return Err(Error::PineconeResponseError(status, typ, msg));
+    }
+    Ok(response.json::<Resp>().await?)
+}
+
+/// Same as [`try_pinecone_request_json`] but returns the raw response text instead of
+/// deserializing it, for endpoints that respond with a plain message.
+pub(crate) async fn try_pinecone_request_text<C, Req>(
+    con: &C,
+    method: Method,
+    expected: StatusCode,
+    base: Option<String>,
+    path: impl AsRef<str>,
+    body: Option<&Req>,
+) -> Result<String>
+where
+    C: Connection,
+    Req: Serialize,
+{
+    let base = base.unwrap_or_else(|| controller_url(&con.credentials().environment));
+    let mut request = con
+        .client()
+        .request(method, format!("{}{}", base, path.as_ref()))
+        .header("Api-Key", &con.credentials().api_key)
+        .header(reqwest::header::USER_AGENT, &con.client_info().user_agent);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if status != expected {
+        return Err(Error::PineconeResponseError(status, String::new(), text));
+    }
+    Ok(text)
+}
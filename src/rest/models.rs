@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The distance metric an index uses to compute similarity scores.
+#[derive(Debug, Clone, Copy)]
+pub enum Metric {
+    Cosine,
+    Euclidean,
+    DotProduct,
+}
+
+impl std::fmt::Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Metric::Cosine => "cosine",
+            Metric::Euclidean => "euclidean",
+            Metric::DotProduct => "dotproduct",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// The cloud provider a serverless index is hosted on.
+#[derive(Debug, Clone, Copy)]
+pub enum Cloud {
+    Aws,
+    Gcp,
+    Azure,
+}
+
+impl std::fmt::Display for Cloud {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Cloud::Aws => "aws",
+            Cloud::Gcp => "gcp",
+            Cloud::Azure => "azure",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl From<Cloud> for String {
+    fn from(value: Cloud) -> Self {
+        value.to_string()
+    }
+}
+
+/// Describes a database. `pods`/`replicas`/`shards`/`pod_type` only apply to pod-based indexes
+/// and are absent from a serverless index's describe response, so they're optional here rather
+/// than assumed present.
+#[derive(Debug, Deserialize)]
+pub struct DatabaseDescription {
+    pub name: String,
+    pub dimension: usize,
+    pub metric: String,
+    pub pods: Option<usize>,
+    pub replicas: Option<usize>,
+    pub shards: Option<usize>,
+    pub pod_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexDescription {
+    pub database: DatabaseDescription,
+    pub status: Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexStats {
+    pub namespaces: std::collections::HashMap<String, Value>,
+    pub dimension: usize,
+    pub index_fullness: f32,
+    pub total_vector_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparseValues {
+    pub indices: Vec<u32>,
+    pub values: Vec<f32>,
+}
+
+/// A single vector record, as stored in and returned by an index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vector {
+    pub id: String,
+    pub values: Vec<f32>,
+    pub sparse_values: Option<SparseValues>,
+    pub metadata: Option<Value>,
+}
+
+/// Body for [`Index::upsert`](crate::rest::Index::upsert).
+#[derive(Debug, Serialize)]
+pub struct VectorRequest {
+    pub namespace: String,
+    pub vectors: Vec<Vector>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpsertResponse {
+    pub upserted_count: usize,
+}